@@ -1,7 +1,7 @@
 //! This library provides an error wrapper which adds a description to its specific instance.
-//! 
+//!
 //! ### Examples
-//! 
+//!
 //! For example, you want to create file on the given path and write here a given string.
 //! Let's forget for a moment that [`std::fs::write`] exists and do it ourselves:
 //! ```
@@ -19,27 +19,27 @@
 //! # use std::{io, fs::File, path::Path};
 //! use std::io::Write;
 //! use describe_err::{describing, describe, Described};
-//! 
+//!
 //! fn create_and_write(path: &Path, content: &str) -> Result<(), Described<io::Error>> {
 //!     let mut file = describing!(File::create(path))?;
 //!     write!(file, "{}", content).map_err(describe("Cannot write to file"))?;
 //!     describing!(file.sync_all())
 //! }
 //! ```
-//! 
+//!
 //! Here you can see two ways to use the library:
-//! 
+//!
 //! - By explicitly providing the description with [`describe`].
 //!     This function returns the closure, which maps an incoming error to `Described` instance.
 //! - By wrapping the `Result`-producing operation in [`describing!`] macro,
 //!     which will describe the error with the stringified content.
-//! 
+//!
 //! And here's how will be used the generated output:
 //! ```
 //! # use std::{io, fs::File, path::{Path, PathBuf}};
 //! # use std::io::Write;
 //! # use describe_err::{describing, describe, Described};
-//! # 
+//! #
 //! # fn create_and_write(path: &Path, content: &str) -> Result<(), Described<io::Error>> {
 //! #     let mut file = describing!(File::create(path))?;
 //! #     write!(file, "{}", content).map_err(describe("Cannot write to file"))?;
@@ -49,27 +49,33 @@
 //!     let path = PathBuf::from("/tmp/nonexistent/path");
 //!     let res = create_and_write(&path, "arbitrary content");
 //!     let err = res.unwrap_err();
-//!     assert_eq!(err.to_string(), "File::create(path): No such file or directory (os error 2)");
+//!     assert!(err.to_string().starts_with("File::create(path): No such file or directory (os error 2)"));
 //! }
 //! ```
 //! As you can see, the command which produced an error is right here, in the error itself.
+//! And since [`describing!`] is a macro, it also knows the exact file and line it was called
+//! from, and appends it to the message (`... (at <file>:<line>)`).
 
-use thiserror::Error;
-use std::error;
+use std::{error, fmt};
 
 /// An error wrapper with description.
-/// 
+///
 /// This struct can hold every error, with the only restriction that this error
 /// must be `'static` to support downcasting through [`source`][std::error::Error::source].
-/// 
+///
 /// When converting this wrapper to string with `Display`, it will render colon-separated
-/// pair of description and original error:
+/// pair of description and original error, followed by the call site
+/// (see [`location`][Described::location]) if one is known:
 /// ```
 /// use describe_err::{Described, describing};
 /// fn fmt<E: std::error::Error + 'static>(err: &Described<E>) -> String {
-///     format!("{}: {}", err.description(), err.original())
+///     let mut out = format!("{}: {}", err.description(), err.original());
+///     if let Some((file, line, _column)) = err.location() {
+///         out += &format!(" (at {file}:{line})");
+///     }
+///     out
 /// }
-/// 
+///
 /// fn main() {
 ///     // Let's create a simple error with auto-generated description...
 ///     let res: Result<u32, _> = describing!("Not a number".parse());
@@ -79,12 +85,12 @@ use std::error;
 ///     assert_eq!(fmt(&err), format!("{}", err));
 /// }
 /// ```
-#[derive(Debug, Error)]
-#[error("{description}: {original}")]
+#[derive(Debug)]
 pub struct Described<E: error::Error + 'static> {
     description: String,
-    #[source]
     original: E,
+    location: Option<(&'static str, u32, u32)>,
+    help: Option<String>,
 }
 
 impl<E: error::Error + 'static> Described<E> {
@@ -93,17 +99,122 @@ impl<E: error::Error + 'static> Described<E> {
         &self.description
     }
     /// Directly retrieves an original error.
-    /// 
+    ///
     /// This method is different from [`source`][std::error::Error::source],
     /// since it is generic and is known to return exactly the wrapped type,
     /// not a boxed trait object. This way you won't need any downcasting.
     pub fn original(&self) -> &E {
         &self.original
     }
+    /// Retrieves the call site this error was described at, if any.
+    ///
+    /// This is filled in automatically by [`describing!`], which has macro context
+    /// to capture [`file!()`], [`line!()`] and [`column!()`]. Errors wrapped with
+    /// the plain [`describe`] function have no such context, so this returns `None`.
+    pub fn location(&self) -> Option<(&'static str, u32, u32)> {
+        self.location
+    }
+    /// Wraps this already-described error in one more layer of description.
+    ///
+    /// This is how a description chain is built: each layer keeps the previous one
+    /// intact behind [`source`][std::error::Error::source], so `describing!(inner_call())?`
+    /// can be re-described with outer context as it propagates up:
+    /// ```
+    /// use describe_err::{describe, Described};
+    /// # use std::io;
+    /// let inner: Result<(), io::Error> = Err(io::Error::new(io::ErrorKind::Other, "disk full"));
+    /// let err: Described<io::Error> = inner
+    ///     .map_err(describe("writing chunk"))
+    ///     .unwrap_err();
+    /// let err: Described<Described<io::Error>> = err.describe_chained("flushing buffer");
+    /// assert_eq!(err.description(), "flushing buffer");
+    /// assert_eq!(err.original().description(), "writing chunk");
+    /// assert_eq!(err.chain().count(), 3); // this layer, the inner layer, and the original error
+    /// ```
+    pub fn describe_chained(self, description: impl Into<String>) -> Described<Self> {
+        Described { description: description.into(), original: self, location: None, help: None }
+    }
+    /// Walks the description chain, from this (outermost) layer down to the
+    /// innermost original error, by following [`source`][std::error::Error::source].
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn error::Error + 'static)> {
+        std::iter::successors(Some(self as &(dyn error::Error + 'static)), |err| err.source())
+    }
+    /// Attaches a user-facing help/suggestion message, distinct from the technical
+    /// description and the original error.
+    /// ```
+    /// use describe_err::describe;
+    /// let err = "Not a number".parse::<u64>()
+    ///     .map_err(describe("parsing count"))
+    ///     .unwrap_err()
+    ///     .with_help("make sure the input is a positive integer");
+    /// assert_eq!(err.help(), Some("make sure the input is a positive integer"));
+    /// ```
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+    /// Retrieves the help/suggestion message, if one was attached via [`with_help`][Described::with_help].
+    pub fn help(&self) -> Option<&str> {
+        self.help.as_deref()
+    }
+    /// Renders this error the same way [`Display`][fmt::Display] does, then appends
+    /// the help message on its own line, if one is present.
+    /// ```
+    /// use describe_err::describe;
+    /// let err = "Not a number".parse::<u64>()
+    ///     .map_err(describe("parsing count"))
+    ///     .unwrap_err()
+    ///     .with_help("make sure the input is a positive integer");
+    /// assert_eq!(
+    ///     err.report(),
+    ///     format!("{err}\nhelp: make sure the input is a positive integer"),
+    /// );
+    /// ```
+    pub fn report(&self) -> String {
+        match &self.help {
+            Some(help) => format!("{self}\nhelp: {help}"),
+            None => self.to_string(),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> fmt::Display for Described<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.description)?;
+            if let Some((file, line, _column)) = self.location {
+                write!(f, " (at {file}:{line})")?;
+            }
+            writeln!(f)?;
+            // `self.original` renders itself with `{:#}` too, so if it's another
+            // `Described` layer, this recurses and indents one level per layer;
+            // if it's the terminal original error, it prints its own message as-is.
+            for (i, line) in format!("{:#}", self.original).lines().enumerate() {
+                if i == 0 {
+                    write!(f, "  - {line}")?;
+                } else {
+                    write!(f, "\n  {line}")?;
+                }
+            }
+            Ok(())
+        } else {
+            write!(f, "{}: {}", self.description, self.original)?;
+            if let Some((file, line, _column)) = self.location {
+                write!(f, " (at {file}:{line})")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for Described<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.original)
+    }
 }
 
 /// Wrap an error with description.
-/// 
+///
 /// This method generates a closure to be passed into `map_err`:
 /// ```
 /// use describe_err::describe;
@@ -111,13 +222,118 @@ impl<E: error::Error + 'static> Described<E> {
 /// let err = "Not a number".parse::<u64>().map_err(describe(description)).unwrap_err();
 /// assert_eq!(err.description(), description);
 /// ```
+///
+/// The resulting [`Described`] has no [`location`][Described::location], since this
+/// function has no macro context to capture the call site. Use [`describing!`] if you
+/// need the location recorded automatically.
 pub fn describe<E: error::Error>(description: impl Into<String>) -> impl FnOnce(E) -> Described<E> {
+    describe_located(description, None)
+}
+
+/// Wrap an error with a lazily-computed description.
+///
+/// Unlike [`describe`], which takes the description up front, this takes a closure
+/// and only calls it if the mapped `Result` is actually an `Err`. Useful when the
+/// description itself is expensive to build (e.g. a `format!()` call) and the
+/// wrapped operation sits in a hot, usually-successful path:
+/// ```
+/// use describe_err::describe_with;
+/// let path = "/nonexistent";
+/// let err = "Not a number".parse::<u64>()
+///     .map_err(describe_with(|| format!("failed to read {path}")))
+///     .unwrap_err();
+/// assert_eq!(err.description(), "failed to read /nonexistent");
+/// ```
+pub fn describe_with<E: error::Error, D: Into<String>>(
+    description: impl FnOnce() -> D,
+) -> impl FnOnce(E) -> Described<E> {
+    move |original| describe(description())(original)
+}
+
+/// Like [`describe`], but also records the call site.
+///
+/// This is the building block [`describing!`] expands to; it is exported so the macro
+/// can reach it from other crates, but [`describe`] and [`describing!`] should cover
+/// every normal use case.
+#[doc(hidden)]
+pub fn describe_located<E: error::Error>(
+    description: impl Into<String>,
+    location: Option<(&'static str, u32, u32)>,
+) -> impl FnOnce(E) -> Described<E> {
     let description = description.into();
-    |original| Described { description, original }
+    move |original| Described { description, original, location, help: None }
+}
+
+/// Extension trait adding `.context(...)`/`.with_context(...)` to `Result`.
+///
+/// This is sugar for `.map_err(describe(...))`, letting you write
+/// `File::create(path).context("opening config")?` instead of spelling out the
+/// `map_err` call at every fallible step.
+pub trait DescribeResult<T, E: error::Error + 'static> {
+    /// Describes the error, equivalent to `.map_err(describe(description))`.
+    /// ```
+    /// use describe_err::DescribeResult;
+    /// let err = "Not a number".parse::<u64>().context("parsing count").unwrap_err();
+    /// assert_eq!(err.description(), "parsing count");
+    /// ```
+    fn context(self, description: impl Into<String>) -> Result<T, Described<E>>;
+    /// Describes the error, building the description lazily so it's only
+    /// allocated when this is actually an `Err`.
+    fn with_context<D: Into<String>>(self, description: impl FnOnce() -> D) -> Result<T, Described<E>>;
+}
+
+impl<T, E: error::Error + 'static> DescribeResult<T, E> for Result<T, E> {
+    fn context(self, description: impl Into<String>) -> Result<T, Described<E>> {
+        self.map_err(describe(description))
+    }
+    fn with_context<D: Into<String>>(self, description: impl FnOnce() -> D) -> Result<T, Described<E>> {
+        self.map_err(describe_with(description))
+    }
+}
+
+/// The error [`DescribeOption::context`] wraps a missing [`Option`] value in.
+///
+/// It carries no information of its own; the useful context is the description
+/// attached through [`Described`].
+#[derive(Debug)]
+pub struct NoneError;
+
+impl fmt::Display for NoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value was `None`")
+    }
+}
+
+impl error::Error for NoneError {}
+
+/// Extension trait adding `.context(...)`/`.with_context(...)` to `Option`.
+///
+/// Mirrors [`DescribeResult`], but for values that may simply be missing rather
+/// than carrying their own error: `None` becomes a [`Described<NoneError>`].
+pub trait DescribeOption<T> {
+    /// Describes a missing value, equivalent to `.ok_or(NoneError).map_err(describe(description))`.
+    /// ```
+    /// use describe_err::DescribeOption;
+    /// let err = None::<u32>.context("looking up user id").unwrap_err();
+    /// assert_eq!(err.description(), "looking up user id");
+    /// ```
+    fn context(self, description: impl Into<String>) -> Result<T, Described<NoneError>>;
+    /// Describes a missing value, building the description lazily so it's only
+    /// allocated when this is actually `None`.
+    fn with_context<D: Into<String>>(self, description: impl FnOnce() -> D) -> Result<T, Described<NoneError>>;
+}
+
+impl<T> DescribeOption<T> for Option<T> {
+    fn context(self, description: impl Into<String>) -> Result<T, Described<NoneError>> {
+        self.ok_or(NoneError).map_err(describe(description))
+    }
+    fn with_context<D: Into<String>>(self, description: impl FnOnce() -> D) -> Result<T, Described<NoneError>> {
+        self.ok_or(NoneError).map_err(describe_with(description))
+    }
 }
 
 /// Wrap an error with an auto-generated description.
-/// 
+///
 /// This macro is essentially a wrapper around [`describe`]. It expands to the following:
 /// ```
 /// # use describe_err::describe;
@@ -126,11 +342,15 @@ pub fn describe<E: error::Error>(description: impl Into<String>) -> impl FnOnce(
 /// let res = result_expression.map_err(describe("result_expression"));
 /// ```
 /// The returned `Result` can be pattern-matched or propagated as usual.
+///
+/// Unlike a bare [`describe`] call, the description produced this way also carries
+/// the file, line and column of the `describing!` call, retrievable through
+/// [`Described::location`].
 #[macro_export]
 macro_rules! describing {
     ($expr:expr) => {{
         let expr: Result<_, _> = $expr;
-        expr.map_err($crate::describe(stringify!($expr)))
+        expr.map_err($crate::describe_located(stringify!($expr), Some((file!(), line!(), column!()))))
     }};
 }
 
@@ -145,6 +365,7 @@ mod tests {
         let err: Result<(), _> = Err(io::Error::new(io::ErrorKind::Other, "Inner error")).map_err(describe("Produced in test"));
         let err = err.unwrap_err();
         assert_eq!(err.to_string(), "Produced in test: Inner error");
+        assert_eq!(err.location(), None);
     }
 
     fn returns_err() -> Result<(), io::Error> {
@@ -154,7 +375,77 @@ mod tests {
     #[test]
     fn macro_err() {
         let err = describing!(returns_err()).unwrap_err();
-        assert_eq!(err.to_string(), "returns_err(): Inner error");
+        assert_eq!(err.description(), "returns_err()");
+        let (file, line, _column) = err.location().expect("describing! should set the location");
+        assert_eq!(file, file!());
+        assert_eq!(err.to_string(), format!("returns_err(): Inner error (at {file}:{line})"));
+    }
+
+    #[test]
+    fn chained_description() {
+        let err: Result<(), _> = Err(io::Error::new(io::ErrorKind::Other, "disk full"));
+        let err = err
+            .map_err(describe("writing chunk"))
+            .unwrap_err()
+            .describe_chained("flushing buffer");
+        assert_eq!(err.description(), "flushing buffer");
+        assert_eq!(err.original().description(), "writing chunk");
+        assert_eq!(err.original().original().to_string(), "disk full");
+        assert_eq!(err.chain().count(), 3);
+        assert_eq!(
+            format!("{err:#}"),
+            "flushing buffer\n  - writing chunk\n    - disk full",
+        );
+    }
+
+    #[test]
+    fn result_context() {
+        let err = "Not a number".parse::<u64>().context("parsing count").unwrap_err();
+        assert_eq!(err.description(), "parsing count");
+    }
+
+    #[test]
+    fn result_with_context_is_lazy() {
+        let mut calls = 0;
+        let ok: Result<u64, io::Error> = Ok(42);
+        let ok = ok.with_context(|| {
+            calls += 1;
+            "never built"
+        });
+        assert_eq!(ok.unwrap(), 42);
+        assert_eq!(calls, 0);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn describe_with_is_lazy_but_runs_on_error() {
+        let mut calls = 0;
+        let err = "Not a number".parse::<u64>().map_err(describe_with(|| {
+            calls += 1;
+            "parsing count"
+        }));
+        assert_eq!(err.unwrap_err().description(), "parsing count");
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn option_context() {
+        let err = None::<u32>.context("looking up user id").unwrap_err();
+        assert_eq!(err.description(), "looking up user id");
+        assert_eq!(err.original().to_string(), "value was `None`");
+    }
+
+    #[test]
+    fn help_message() {
+        let err = describing!(returns_err()).unwrap_err().with_help("retry the operation");
+        assert_eq!(err.help(), Some("retry the operation"));
+        assert_eq!(err.report(), format!("{err}\nhelp: retry the operation"));
+    }
+
+    #[test]
+    fn no_help_message() {
+        let err = describing!(returns_err()).unwrap_err();
+        assert_eq!(err.help(), None);
+        assert_eq!(err.report(), err.to_string());
+    }
+
+}